@@ -0,0 +1,331 @@
+//! Background timer daemon: holds a `TimerRegistry` behind a Unix domain
+//! socket so multiple client shells can `start`/`stop`/`lap`/`elapsed` named
+//! timers that live in one authoritative place instead of each shell holding
+//! its own in-process registry.
+//!
+//! The wire protocol is deliberately simple: one command per line in, its
+//! response out (several lines for `laps`, `stats`, `timers` and csv
+//! `export`), using the same verbs and `<verb> [name] [rest]` shape as
+//! `dispatch` in `main.rs`.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use timer_cli::{format_duration, TimerError, TimerErrorKind, TimerRegistry};
+
+const DEFAULT_TIMER: &str = "default";
+
+/// Default socket path under the user's runtime dir (`$XDG_RUNTIME_DIR`, or
+/// `/tmp` when unset).
+pub fn default_socket_path() -> PathBuf {
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+    PathBuf::from(runtime_dir).join("timer-cli.sock")
+}
+
+/// Execute one daemon protocol line against the shared registry, returning
+/// the response line. Mirrors `dispatch`'s verbs (`start`/`stop`/`elapsed`/
+/// `lap`/`laps`/`reset`/`countdown`/`stats`/`export`/`timers`) but returns a
+/// string instead of printing to stdout, since the caller owns the socket
+/// write.
+pub fn handle_command(reg: &mut TimerRegistry, input: &str) -> String {
+    let mut parts = input.split_whitespace();
+    let cmd = parts.next().unwrap_or("");
+    match cmd {
+        "start" => ok_or_err(reg.start(parts.next().unwrap_or(DEFAULT_TIMER)).map(|_| ())),
+        "stop" => ok_or_err(reg.stop(parts.next().unwrap_or(DEFAULT_TIMER))),
+        "reset" => ok_or_err(reg.reset(parts.next().unwrap_or(DEFAULT_TIMER))),
+        "elapsed" => match reg.elapsed(parts.next().unwrap_or(DEFAULT_TIMER)) {
+            Ok(d) => format_duration(d),
+            Err(e) => format_err(&e),
+        },
+        "lap" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            let label = parts.next().map(|s| s.to_string());
+            ok_or_err(reg.lap(name, label))
+        }
+        "laps" => format_laps(reg, parts.next().unwrap_or(DEFAULT_TIMER)),
+        "countdown" => {
+            let first = match parts.next() {
+                Some(a) => a,
+                None => return format_err(&TimerError(TimerErrorKind::Invalid)),
+            };
+            // `countdown <d>` targets the default timer, `countdown <name> <d>` a named one.
+            let (name, arg) = match parts.next() {
+                Some(a) => (first, a),
+                None => (DEFAULT_TIMER, first),
+            };
+            match timer_cli::parse_duration(arg).and_then(|target| reg.countdown(name, target)) {
+                Ok(_) => "ok".to_string(),
+                Err(e) => format_err(&e),
+            }
+        }
+        "stats" => format_stats(reg, parts.next().unwrap_or(DEFAULT_TIMER)),
+        "export" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            let fmt = parts.next().unwrap_or("json");
+            format_export(reg, name, fmt)
+        }
+        "timers" => format_timers(reg),
+        _ => format_err(&TimerError(TimerErrorKind::Invalid)),
+    }
+}
+
+fn format_stats(reg: &TimerRegistry, name: &str) -> String {
+    let deltas = match reg.lap_deltas(name) {
+        Ok(d) => d,
+        Err(e) => return format_err(&e),
+    };
+    let stats = match timer_cli::lap_stats(&deltas) {
+        Ok(s) => s,
+        Err(e) => return format_err(&e),
+    };
+    [
+        format!("laps   {}", stats.count),
+        format!("min    {}", format_duration(Duration::from_millis(stats.min_ms))),
+        format!("mean   {}", format_duration(Duration::from_millis(stats.mean_ms.round() as u64))),
+        format!("max    {}", format_duration(Duration::from_millis(stats.max_ms))),
+        format!("p50    {}", format_duration(Duration::from_millis(stats.p50_ms))),
+        format!("p90    {}", format_duration(Duration::from_millis(stats.p90_ms))),
+        format!("p99    {}", format_duration(Duration::from_millis(stats.p99_ms))),
+        format!("p99.9  {}", format_duration(Duration::from_millis(stats.p999_ms))),
+    ]
+    .join("\n")
+}
+
+/// `export <name> [json|csv]` over the wire. `influx` is not served here and
+/// is answered with `err Invalid`; `main.rs`'s `export_laps_influx` only
+/// works on the in-process registry, not on a daemon's timers.
+fn format_export(reg: &TimerRegistry, name: &str, fmt: &str) -> String {
+    let laps = match reg.laps(name) {
+        Ok(laps) => laps,
+        Err(e) => return format_err(&e),
+    };
+    match fmt {
+        "json" => serde_json::to_string(laps).unwrap_or_else(|_| format_err(&TimerError(TimerErrorKind::Invalid))),
+        "csv" => {
+            let mut wtr = csv::Writer::from_writer(Vec::new());
+            wtr.write_record(["index", "time_ms", "label"]).unwrap();
+            for lap in laps {
+                wtr.write_record([
+                    lap.index.to_string(),
+                    lap.at_ms.to_string(),
+                    lap.label.clone().unwrap_or_default(),
+                ])
+                .unwrap();
+            }
+            let bytes = wtr.into_inner().unwrap_or_default();
+            String::from_utf8_lossy(&bytes).trim_end().to_string()
+        }
+        _ => format_err(&TimerError(TimerErrorKind::Invalid)),
+    }
+}
+
+fn ok_or_err(r: Result<(), TimerError>) -> String {
+    match r {
+        Ok(()) => "ok".to_string(),
+        Err(e) => format_err(&e),
+    }
+}
+
+fn format_err(e: &TimerError) -> String {
+    format!("err {:?}", e.0)
+}
+
+fn format_laps(reg: &TimerRegistry, name: &str) -> String {
+    let laps = match reg.laps(name) {
+        Ok(laps) => laps,
+        Err(e) => return format_err(&e),
+    };
+    if laps.is_empty() {
+        return "(no laps)".to_string();
+    }
+    let mut prev_ms: u128 = 0;
+    let mut lines = Vec::with_capacity(laps.len());
+    for lap in laps {
+        let delta_ms = lap.at_ms.saturating_sub(prev_ms);
+        lines.push(format!(
+            "{} {} {} {}",
+            lap.index,
+            format_duration(Duration::from_millis(lap.at_ms as u64)),
+            format_duration(Duration::from_millis(delta_ms as u64)),
+            lap.label.clone().unwrap_or_default()
+        ));
+        prev_ms = lap.at_ms;
+    }
+    lines.join("\n")
+}
+
+fn format_timers(reg: &TimerRegistry) -> String {
+    let timers = reg.timers();
+    if timers.is_empty() {
+        return "(no timers)".to_string();
+    }
+    timers
+        .into_iter()
+        .map(|t| {
+            let remaining = t.remaining.map(format_duration).unwrap_or_else(|| "-".to_string());
+            format!("{} {} {}", t.name, format_duration(t.elapsed), remaining)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(unix)]
+mod transport {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Bind `socket_path` and serve client connections until the process is
+    /// killed. Each connection gets its own thread; all connections share one
+    /// `TimerRegistry` behind a mutex, ticked by a dedicated background
+    /// thread so scheduled countdowns fire even between client connections.
+    pub fn run(socket_path: &Path) -> std::io::Result<()> {
+        if socket_path.exists() {
+            std::fs::remove_file(socket_path)?;
+        }
+        let listener = UnixListener::bind(socket_path)?;
+        println!("timer-cli daemon listening on {}", socket_path.display());
+        let registry = Arc::new(Mutex::new(TimerRegistry::new()));
+
+        {
+            let registry = registry.clone();
+            thread::spawn(move || loop {
+                thread::sleep(TimerRegistry::DEFAULT_TICK);
+                let fired = registry.lock().unwrap().tick();
+                for (name, target) in fired {
+                    notify_countdown_elapsed(&name, target);
+                }
+            });
+        }
+
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            let registry = registry.clone();
+            thread::spawn(move || {
+                let _ = serve_client(stream, registry);
+            });
+        }
+        Ok(())
+    }
+
+    fn notify_countdown_elapsed(name: &str, target: Duration) {
+        let body = format!("Countdown '{name}' ({}) finished", format_duration(target));
+        let sent = notify_rust::Notification::new().summary("timer-cli daemon").body(&body).show().is_ok();
+        if !sent {
+            eprint!("\x07");
+            eprintln!("timer-cli: {body}");
+        }
+    }
+
+    fn serve_client(stream: UnixStream, registry: Arc<Mutex<TimerRegistry>>) -> std::io::Result<()> {
+        let mut writer = stream.try_clone()?;
+        let reader = BufReader::new(stream);
+        for line in reader.lines() {
+            let line = line?;
+            let cmd = line.trim();
+            if cmd.is_empty() {
+                continue;
+            }
+            let response = {
+                let mut reg = registry.lock().unwrap();
+                handle_command(&mut reg, cmd)
+            };
+            writeln!(writer, "{response}")?;
+        }
+        Ok(())
+    }
+
+    /// Connect to a running daemon, send one command line, and return its
+    /// whole response (every line of it). Retries briefly so a client started
+    /// just after the daemon doesn't fail on the first attempt.
+    pub fn query(socket_path: &Path, cmd: &str) -> std::io::Result<String> {
+        let mut stream = connect_with_retry(socket_path, 5)?;
+        writeln!(stream, "{cmd}")?;
+        // Half-close so `serve_client` ends after this command and closes the
+        // connection once the response is written; read up to that close.
+        stream.shutdown(std::net::Shutdown::Write)?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        Ok(response.trim_end().to_string())
+    }
+
+    fn connect_with_retry(socket_path: &Path, attempts: u32) -> std::io::Result<UnixStream> {
+        let mut last_err = None;
+        for attempt in 0..attempts {
+            match UnixStream::connect(socket_path) {
+                Ok(stream) => return Ok(stream),
+                Err(e) => {
+                    last_err = Some(e);
+                    if attempt + 1 < attempts {
+                        thread::sleep(std::time::Duration::from_millis(100));
+                    }
+                }
+            }
+        }
+        Err(last_err.unwrap())
+    }
+}
+
+#[cfg(not(unix))]
+mod transport {
+    use std::io;
+    use std::path::Path;
+
+    pub fn run(_socket_path: &Path) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "timer-cli daemon: named-pipe transport for non-Unix platforms is not implemented yet",
+        ))
+    }
+
+    pub fn query(_socket_path: &Path, _cmd: &str) -> io::Result<String> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "timer-cli daemon: named-pipe transport for non-Unix platforms is not implemented yet",
+        ))
+    }
+}
+
+pub use transport::{query, run};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_then_elapsed_round_trips_through_the_wire_protocol() {
+        let mut reg = TimerRegistry::new();
+        assert_eq!(handle_command(&mut reg, "start build"), "ok");
+        // exact value is a moving target (real elapsed time); just check it's
+        // formatted as HH:MM:SS.mmm rather than an error line.
+        assert!(handle_command(&mut reg, "elapsed build").contains(':'));
+    }
+
+    #[test]
+    fn unknown_verb_reports_invalid() {
+        let mut reg = TimerRegistry::new();
+        assert_eq!(handle_command(&mut reg, "bogus"), "err Invalid");
+    }
+
+    #[test]
+    fn elapsed_on_missing_timer_reports_invalid() {
+        let mut reg = TimerRegistry::new();
+        assert_eq!(handle_command(&mut reg, "elapsed missing"), "err Invalid");
+    }
+
+    #[test]
+    fn stats_and_export_are_wired_through() {
+        let mut reg = TimerRegistry::new();
+        reg.start("build").unwrap();
+        reg.lap("build", None).unwrap();
+        assert!(handle_command(&mut reg, "stats build").starts_with("laps   1"));
+        assert!(handle_command(&mut reg, "export build json").starts_with('['));
+    }
+}
@@ -1,5 +1,8 @@
 use std::time::{Duration, Instant};
 
+mod registry;
+pub use registry::{TimerId, TimerRegistry, TimerSnapshot, TimingWheel};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimerErrorKind {
     AlreadyRunning,
@@ -89,6 +92,307 @@ impl Timer {
     }
 
     pub fn laps(&self) -> &[Lap] { &self.laps }
+
+    /// Per-lap deltas in milliseconds, in lap order (the first lap's delta is
+    /// its own `at_ms`, same convention `print_laps` uses).
+    pub fn lap_deltas(&self) -> Vec<u64> {
+        let mut prev_ms: u128 = 0;
+        let mut deltas = Vec::with_capacity(self.laps.len());
+        for lap in &self.laps {
+            deltas.push(lap.at_ms.saturating_sub(prev_ms) as u64);
+            prev_ms = lap.at_ms;
+        }
+        deltas
+    }
+
+    /// Remaining time until `target` is reached, given the current elapsed
+    /// time. Saturates at zero once `target` has passed, so callers can poll
+    /// this in a loop without checking for overflow themselves.
+    pub fn countdown(&self, target: Duration) -> Duration {
+        target.saturating_sub(self.elapsed())
+    }
+}
+
+/// Which interval a `Pomodoro` is currently in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+/// Durations and cadence for a `Pomodoro` session.
+#[derive(Debug, Clone)]
+pub struct PomodoroConfig {
+    pub work: Duration,
+    pub short_break: Duration,
+    pub long_break: Duration,
+    /// Number of work cycles between long breaks (every Nth break is long).
+    pub cycles_before_long_break: u32,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work: Duration::from_secs(25 * 60),
+            short_break: Duration::from_secs(5 * 60),
+            long_break: Duration::from_secs(15 * 60),
+            cycles_before_long_break: 4,
+        }
+    }
+}
+
+/// Totals for a finished or in-progress `Pomodoro` session.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PomodoroSummary {
+    // total focused time, stored as milliseconds for simple serialization, same as `Lap::at_ms`
+    pub focused_ms: u128,
+    pub breaks_taken: u32,
+    pub cycles_completed: u32,
+}
+
+/// Sequences alternating work/break intervals on top of a `Timer`, tracking
+/// the current phase and cycle count so a caller can drive a pomodoro loop.
+pub struct Pomodoro {
+    config: PomodoroConfig,
+    timer: Timer,
+    phase: PomodoroPhase,
+    cycle: u32,
+    focused: Duration,
+    breaks_taken: u32,
+    running: bool,
+}
+
+impl Pomodoro {
+    pub fn new(config: PomodoroConfig) -> Self {
+        Self {
+            config,
+            timer: Timer::new(),
+            phase: PomodoroPhase::Work,
+            cycle: 0,
+            focused: Duration::ZERO,
+            breaks_taken: 0,
+            running: false,
+        }
+    }
+
+    /// Start (or resume) the current phase's timer.
+    pub fn start(&mut self) -> Result<(), TimerError> {
+        self.running = true;
+        self.timer.start()
+    }
+
+    /// Stop the session, keeping phase/cycle state so it can be resumed.
+    pub fn stop(&mut self) -> Result<(), TimerError> {
+        self.running = false;
+        self.timer.stop()
+    }
+
+    pub fn phase(&self) -> PomodoroPhase {
+        self.phase
+    }
+
+    pub fn cycle(&self) -> u32 {
+        self.cycle
+    }
+
+    /// Target duration of the current phase.
+    pub fn target(&self) -> Duration {
+        match self.phase {
+            PomodoroPhase::Work => self.config.work,
+            PomodoroPhase::ShortBreak => self.config.short_break,
+            PomodoroPhase::LongBreak => self.config.long_break,
+        }
+    }
+
+    /// Time left in the current phase.
+    pub fn remaining(&self) -> Duration {
+        self.timer.countdown(self.target())
+    }
+
+    /// If the current phase's target has elapsed, credit it and move to the
+    /// next phase (work -> short/long break -> work, every `cycles_before_long_break`th
+    /// break is long). Returns the phase that just completed, or `None` if
+    /// still in progress.
+    pub fn advance_if_elapsed(&mut self) -> Option<PomodoroPhase> {
+        if self.timer.elapsed() < self.target() {
+            return None;
+        }
+        Some(self.finish_phase())
+    }
+
+    /// Abort the current phase early and move straight to the next one. A
+    /// skipped work phase still counts as a completed cycle and credits the
+    /// time worked so far to the focused total; a skipped break counts as taken.
+    pub fn skip(&mut self) -> PomodoroPhase {
+        self.finish_phase()
+    }
+
+    fn finish_phase(&mut self) -> PomodoroPhase {
+        let finished = self.phase;
+        match finished {
+            PomodoroPhase::Work => {
+                self.focused += self.timer.elapsed().min(self.config.work);
+                self.cycle += 1;
+                let cadence = self.config.cycles_before_long_break.max(1);
+                self.phase = if self.cycle.is_multiple_of(cadence) {
+                    PomodoroPhase::LongBreak
+                } else {
+                    PomodoroPhase::ShortBreak
+                };
+            }
+            PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => {
+                self.breaks_taken += 1;
+                self.phase = PomodoroPhase::Work;
+            }
+        }
+        self.timer.reset();
+        if self.running {
+            let _ = self.timer.start();
+        }
+        finished
+    }
+
+    /// Session totals so far, suitable for export alongside `Timer::laps()`.
+    pub fn summary(&self) -> PomodoroSummary {
+        PomodoroSummary {
+            focused_ms: self.focused.as_millis(),
+            breaks_taken: self.breaks_taken,
+            cycles_completed: self.cycle,
+        }
+    }
+}
+
+/// Parse a human-readable duration, accepting both the compact form
+/// (`1h30m`, `90s`, `250ms`, `2m30s`) and the colon form that mirrors
+/// `format_duration`'s output (`HH:MM:SS.mmm`, `MM:SS`, `:SS`). Either `.` or
+/// `,` is accepted as the decimal separator in the colon form, so values
+/// copied from logs or subtitle files parse cleanly. Unknown unit tokens in
+/// the compact form are rejected with `TimerErrorKind::Invalid`.
+pub fn parse_duration(input: &str) -> Result<Duration, TimerError> {
+    let s = input.trim();
+    if s.is_empty() {
+        return Err(TimerError(TimerErrorKind::Invalid));
+    }
+    if s.contains(':') {
+        parse_colon_form(s)
+    } else {
+        parse_compact_form(s)
+    }
+}
+
+fn parse_colon_form(s: &str) -> Result<Duration, TimerError> {
+    let normalized = s.replace(',', ".");
+    let fields: Vec<&str> = normalized.split(':').collect();
+    if fields.len() > 3 {
+        return Err(TimerError(TimerErrorKind::Invalid));
+    }
+    let mut iter = fields.iter().rev();
+
+    let secs_field = iter.next().ok_or(TimerError(TimerErrorKind::Invalid))?;
+    let (whole_secs, ms) = match secs_field.split_once('.') {
+        Some((whole, frac)) => {
+            let whole = parse_field(whole)?;
+            let mut frac = frac.to_string();
+            if frac.is_empty() || !frac.chars().all(|c| c.is_ascii_digit()) {
+                return Err(TimerError(TimerErrorKind::Invalid));
+            }
+            frac.truncate(3);
+            while frac.len() < 3 {
+                frac.push('0');
+            }
+            let ms: u64 = frac.parse().map_err(|_| TimerError(TimerErrorKind::Invalid))?;
+            (whole, ms)
+        }
+        None => (parse_field(secs_field)?, 0),
+    };
+    let minutes = iter.next().map(|m| parse_field(m)).transpose()?.unwrap_or(0);
+    let hours = iter.next().map(|h| parse_field(h)).transpose()?.unwrap_or(0);
+
+    let total_ms = hours
+        .checked_mul(3_600_000)
+        .and_then(|h| minutes.checked_mul(60_000).and_then(|m| h.checked_add(m)))
+        .and_then(|hm| whole_secs.checked_mul(1_000).and_then(|s| hm.checked_add(s)))
+        .and_then(|hms| hms.checked_add(ms))
+        .ok_or(TimerError(TimerErrorKind::Invalid))?;
+    Ok(Duration::from_millis(total_ms))
+}
+
+/// Parse one `HH`/`MM`/`SS` colon-form field, treating an empty field (as in
+/// the leading blank of `:SS`) as zero.
+fn parse_field(field: &str) -> Result<u64, TimerError> {
+    if field.is_empty() {
+        return Ok(0);
+    }
+    field.parse().map_err(|_| TimerError(TimerErrorKind::Invalid))
+}
+
+/// Parse the compact form by summing consecutive `<number><unit>` tokens,
+/// e.g. `1h30m` or `2m30s`.
+fn parse_compact_form(input: &str) -> Result<Duration, TimerError> {
+    let mut rest = input;
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(rest.len());
+        if digits_end == 0 {
+            return Err(TimerError(TimerErrorKind::Invalid));
+        }
+        let (num_str, tail) = rest.split_at(digits_end);
+        let unit_end = tail.find(|c: char| c.is_ascii_digit()).unwrap_or(tail.len());
+        let (unit, next) = tail.split_at(unit_end);
+        let value: f64 = num_str.parse().map_err(|_| TimerError(TimerErrorKind::Invalid))?;
+        let ms = match unit {
+            "h" => value * 3_600_000.0,
+            "m" => value * 60_000.0,
+            "s" => value * 1_000.0,
+            "ms" => value,
+            _ => return Err(TimerError(TimerErrorKind::Invalid)),
+        };
+        total = total
+            .checked_add(Duration::from_millis(ms.round() as u64))
+            .ok_or(TimerError(TimerErrorKind::Invalid))?;
+        rest = next;
+    }
+    Ok(total)
+}
+
+/// Min/mean/max and tail percentiles of a set of lap deltas, in milliseconds.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LapStats {
+    pub count: u64,
+    pub min_ms: u64,
+    pub mean_ms: f64,
+    pub max_ms: u64,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+    pub p999_ms: u64,
+}
+
+/// Summarize lap deltas (as produced by `Timer::lap_deltas`) with an HDR
+/// histogram: O(1) recording and cheap quantile queries, at 3 significant
+/// figures of precision. Zero-millisecond deltas are clamped to 1ms, the
+/// histogram's lower bound. Returns `TimerErrorKind::Invalid` if `deltas` is
+/// empty, since percentiles aren't meaningful with no samples.
+pub fn lap_stats(deltas: &[u64]) -> Result<LapStats, TimerError> {
+    if deltas.is_empty() {
+        return Err(TimerError(TimerErrorKind::Invalid));
+    }
+    let mut hist = hdrhistogram::Histogram::<u64>::new_with_bounds(1, 3_600_000, 3)
+        .map_err(|_| TimerError(TimerErrorKind::Invalid))?;
+    for &d in deltas {
+        hist.record(d.max(1)).map_err(|_| TimerError(TimerErrorKind::Invalid))?;
+    }
+    Ok(LapStats {
+        count: hist.len(),
+        min_ms: hist.min(),
+        mean_ms: hist.mean(),
+        max_ms: hist.max(),
+        p50_ms: hist.value_at_percentile(50.0),
+        p90_ms: hist.value_at_percentile(90.0),
+        p99_ms: hist.value_at_percentile(99.0),
+        p999_ms: hist.value_at_percentile(99.9),
+    })
 }
 
 /// Format a Duration as HH:MM:SS.mmm (integer milliseconds)
@@ -1,11 +1,18 @@
 use std::io::{self, Write};
+use std::path::PathBuf;
 use std::process::{ExitCode, Command};
-use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
 use clap::{Parser, Subcommand};
-use timer_cli::{format_duration, Timer, TimerError, TimerErrorKind};
+use timer_cli::{format_duration, parse_duration, Pomodoro, PomodoroConfig, PomodoroPhase, Timer, TimerError, TimerErrorKind, TimerRegistry};
+
+mod daemon;
+
+/// Name used when a command omits the timer name, so `start`/`elapsed`/...
+/// keep working the way they did before named timers existed.
+const DEFAULT_TIMER: &str = "default";
 
 #[derive(Parser)]
 #[command(name = "timer-cli", version = env!("CARGO_PKG_VERSION"), about = "Timer CLI: REPL + batch, watch, lap/export, measure")]
@@ -14,8 +21,21 @@ struct Cli {
     command: Option<Commands>,
 
     /// (Opsional) Kompat: jalankan perintah langsung tanpa subcommand `run`.
+    /// `trailing_var_arg` berarti token pertama di sini mengakhiri parsing
+    /// flag, jadi `--daemon`/`--socket` harus ditulis SEBELUM daftar perintah
+    /// ini (atau pakai subcommand `run`, yang menerimanya di posisi mana saja).
     #[arg(trailing_var_arg = true)]
     legacy_cmds: Vec<String>,
+
+    /// Kirim perintah ke `timer-cli daemon` yang sedang berjalan alih-alih memakai registry in-process.
+    /// Untuk kompat-lama (tanpa subcommand) harus ditulis sebelum daftar perintah.
+    #[arg(long, global = true)]
+    daemon: bool,
+
+    /// Path socket daemon (default: $XDG_RUNTIME_DIR/timer-cli.sock, atau /tmp jika tak diset).
+    /// Sama seperti `--daemon`: untuk kompat-lama harus ditulis sebelum daftar perintah.
+    #[arg(long, global = true)]
+    socket: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -24,36 +44,117 @@ enum Commands {
     Run { cmds: Vec<String> },
     /// REPL interaktif eksplisit (tanpa argumen juga masuk REPL)
     Interactive,
+    /// Sesi pomodoro: interval work/break bergantian + notifikasi
+    Pomodoro {
+        /// Durasi fase kerja, mis. 25m
+        #[arg(long, default_value = "25m")]
+        work: String,
+        /// Durasi istirahat pendek, mis. 5m
+        #[arg(long = "short-break", default_value = "5m")]
+        short_break: String,
+        /// Durasi istirahat panjang, mis. 15m
+        #[arg(long = "long-break", default_value = "15m")]
+        long_break: String,
+        /// Jumlah siklus kerja sebelum istirahat panjang
+        #[arg(long, default_value_t = 4)]
+        cycles: u32,
+        /// Format ringkasan sesi setelah selesai, lewat jalur export yang sama
+        /// dengan `export [nama] [json|csv|influx]`
+        #[arg(long, default_value = "json")]
+        export: String,
+    },
+    /// Jalankan daemon: simpan registry timer bernama di belakang Unix domain
+    /// socket agar bisa dipakai bersama dari beberapa shell (lihat flag global `--socket`)
+    Daemon,
+}
+
+fn socket_path(cli: &Cli) -> PathBuf {
+    cli.socket.clone().unwrap_or_else(daemon::default_socket_path)
 }
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    if matches!(cli.command, Some(Commands::Daemon)) {
+        return match daemon::run(&socket_path(&cli)) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("daemon error: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let daemon_mode = cli.daemon;
+    let socket = socket_path(&cli);
+
     // Subcommand eksplisit
     if let Some(Commands::Run { cmds }) = &cli.command {
-        return run_batch(cmds.clone());
+        return run_batch(cmds.clone(), daemon_mode, &socket);
     }
     if matches!(cli.command, Some(Commands::Interactive)) {
         return run_repl();
     }
+    if let Some(Commands::Pomodoro { work, short_break, long_break, cycles, export }) = &cli.command {
+        return run_pomodoro(work, short_break, long_break, *cycles, export);
+    }
 
     // Kompat-lama: argumen langsung tanpa subcommand
     if !cli.legacy_cmds.is_empty() {
-        return run_batch(cli.legacy_cmds.clone());
+        return run_batch(cli.legacy_cmds.clone(), daemon_mode, &socket);
     }
 
     // Default: REPL
     run_repl()
 }
 
-fn run_batch(cmds: Vec<String>) -> ExitCode {
+/// Spawn the ~100ms background tick thread that drives every scheduled
+/// countdown in `reg`, firing a notification as each one expires.
+fn spawn_tick_thread(reg: Arc<Mutex<TimerRegistry>>) -> thread::JoinHandle<()> {
+    thread::spawn(move || loop {
+        thread::sleep(TimerRegistry::DEFAULT_TICK);
+        let fired = reg.lock().unwrap().tick();
+        for (name, target) in fired {
+            notify_countdown_elapsed(&name, target);
+        }
+    })
+}
+
+fn run_batch(cmds: Vec<String>, daemon_mode: bool, socket: &std::path::Path) -> ExitCode {
     if cmds.is_empty() {
         eprintln!("error: no commands. Use `timer-cli -h` for help.");
         return ExitCode::from(2);
     }
-    let mut t = Timer::new();
+    // `legacy_cmds` is `trailing_var_arg`, so once the first command token is
+    // seen clap stops parsing flags and swallows everything after it
+    // verbatim -- including `--daemon`/`--socket`. Catch that here
+    // with a clear error instead of silently trying to run "--daemon" as a
+    // timer command.
+    if let Some(stray) = cmds.iter().find(|c| *c == "--daemon" || c.starts_with("--socket")) {
+        let rest: Vec<&str> = cmds.iter().map(String::as_str).filter(|c| *c != stray).collect();
+        eprintln!(
+            "error: `{stray}` was parsed as a command, not a flag, because flags after the command list aren't recognized here.\nPut it before the commands instead: `timer-cli {stray} {}` (or `timer-cli run {} {stray}`).",
+            rest.join(" "),
+            rest.join(" "),
+        );
+        return ExitCode::from(2);
+    }
+    if daemon_mode {
+        for cmd in cmds {
+            match daemon::query(socket, &cmd) {
+                Ok(response) => println!("{response}"),
+                Err(e) => {
+                    eprintln!("error: {e} (cmd: {cmd})");
+                    return ExitCode::from(1);
+                }
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+    let reg = Arc::new(Mutex::new(TimerRegistry::new()));
+    let _tick = spawn_tick_thread(reg.clone());
     for cmd in cmds {
-        if let Err(e) = dispatch(&mut t, &cmd) {
+        if let Err(e) = dispatch(&reg, &cmd) {
             eprintln!("error: {:?} (cmd: {cmd})", e);
             return ExitCode::from(1);
         }
@@ -62,8 +163,9 @@ fn run_batch(cmds: Vec<String>) -> ExitCode {
 }
 
 fn run_repl() -> ExitCode {
-    println!("Timer REPL. Perintah: start|stop|reset|elapsed|watch|lap [label]|laps|export [json|csv]|measure -- <cmd...>|help|exit");
-    let mut t = Timer::new();
+    println!("Timer REPL. Perintah: start [nama]|stop [nama]|reset [nama]|elapsed [nama]|watch [nama]|countdown [nama] <d>|lap [nama] [label]|laps [nama]|stats [nama]|export [nama] [json|csv|influx]|timers|measure -- <cmd...>|help|exit");
+    let reg = Arc::new(Mutex::new(TimerRegistry::new()));
+    let _tick = spawn_tick_thread(reg.clone());
     let stdin = io::stdin();
     loop {
         print!("> ");
@@ -80,25 +182,63 @@ fn run_repl() -> ExitCode {
             print_help();
             continue;
         }
-        if let Err(e) = dispatch(&mut t, s) {
+        if let Err(e) = dispatch(&reg, s) {
             eprintln!("error: {:?} (cmd: {s})", e);
         }
     }
     ExitCode::SUCCESS
 }
 
-fn dispatch(t: &mut Timer, input: &str) -> Result<(), TimerError> {
+/// Parses `<verb> [name] [rest...]`, defaulting the name to `DEFAULT_TIMER`
+/// so single-timer usage from before the registry existed keeps working.
+fn dispatch(reg: &Arc<Mutex<TimerRegistry>>, input: &str) -> Result<(), TimerError> {
     let mut parts = input.split_whitespace();
     let cmd = parts.next().unwrap_or("");
     match cmd {
-        "start"   => t.start(),
-        "stop"    => t.stop(),
-        "reset"   => { t.reset(); Ok(()) }
-        "elapsed" => { println!("{}", format_duration(t.elapsed())); Ok(()) }
-        "watch"   => { run_watch(t); Ok(()) }
-        "lap"     => { let label = parts.next().map(|s| s.to_string()); t.lap(label) }
-        "laps"    => { print_laps(t); Ok(()) }
-        "export"  => { let fmt = parts.next().unwrap_or("json"); export_laps(t, fmt)?; Ok(()) }
+        "start" => { reg.lock().unwrap().start(parts.next().unwrap_or(DEFAULT_TIMER))?; Ok(()) }
+        "stop" => reg.lock().unwrap().stop(parts.next().unwrap_or(DEFAULT_TIMER)),
+        "reset" => reg.lock().unwrap().reset(parts.next().unwrap_or(DEFAULT_TIMER)),
+        "elapsed" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            let elapsed = reg.lock().unwrap().elapsed(name)?;
+            println!("{}", format_duration(elapsed));
+            Ok(())
+        }
+        "watch" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER).to_string();
+            run_watch(reg, &name)
+        }
+        "countdown" => {
+            let first = parts.next().ok_or(TimerError(TimerErrorKind::Invalid))?;
+            // `countdown <d>` targets the default timer, `countdown <name> <d>` a named one.
+            let (name, arg) = match parts.next() {
+                Some(arg) => (first.to_string(), arg),
+                None => (DEFAULT_TIMER.to_string(), first),
+            };
+            let target = parse_duration(arg)?;
+            reg.lock().unwrap().countdown(&name, target)?;
+            println!("countdown {name}: dijadwalkan, notifikasi saat {} habis", format_duration(target));
+            Ok(())
+        }
+        "lap" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            let label = parts.next().map(|s| s.to_string());
+            reg.lock().unwrap().lap(name, label)
+        }
+        "laps" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            print_laps(&reg.lock().unwrap(), name)
+        }
+        "stats" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            print_stats(&reg.lock().unwrap(), name)
+        }
+        "export" => {
+            let name = parts.next().unwrap_or(DEFAULT_TIMER);
+            let fmt = parts.next().unwrap_or("json");
+            export_laps(&reg.lock().unwrap(), name, fmt)
+        }
+        "timers" => { print_timers(&reg.lock().unwrap()); Ok(()) }
         "measure" => { let cmdline: Vec<String> = parts.map(|s| s.to_string()).collect(); measure_command(cmdline)?; Ok(()) }
         "-h" | "--help" => { print_help(); Ok(()) }
         "-V" | "--version" => { println!("timer-cli v{}", env!("CARGO_PKG_VERSION")); Ok(()) }
@@ -106,9 +246,97 @@ fn dispatch(t: &mut Timer, input: &str) -> Result<(), TimerError> {
     }
 }
 
-fn run_watch(t: &mut Timer) {
+enum PomodoroControl {
+    Skip,
+    Stop,
+}
+
+fn run_pomodoro(work: &str, short_break: &str, long_break: &str, cycles: u32, export_fmt: &str) -> ExitCode {
+    let config = match (parse_duration(work), parse_duration(short_break), parse_duration(long_break)) {
+        (Ok(work), Ok(short_break), Ok(long_break)) => {
+            PomodoroConfig { work, short_break, long_break, cycles_before_long_break: cycles }
+        }
+        _ => {
+            eprintln!("error: invalid duration in --work/--short-break/--long-break");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut pomo = Pomodoro::new(config);
+    let _ = pomo.start();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        loop {
+            let mut line = String::new();
+            if stdin.read_line(&mut line).unwrap_or(0) == 0 {
+                let _ = tx.send(PomodoroControl::Stop);
+                break;
+            }
+            match line.trim() {
+                "skip" => { let _ = tx.send(PomodoroControl::Skip); }
+                "stop" | "quit" | "exit" => {
+                    let _ = tx.send(PomodoroControl::Stop);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
+    println!("Pomodoro dimulai. Ketik `skip` untuk lompat fase, `stop` untuk selesai.");
+    loop {
+        if let Ok(msg) = rx.try_recv() {
+            match msg {
+                PomodoroControl::Skip => {
+                    let finished = pomo.skip();
+                    println!("\n{:?} dilewati, masuk {:?}", finished, pomo.phase());
+                }
+                PomodoroControl::Stop => break,
+            }
+        }
+        if let Some(finished) = pomo.advance_if_elapsed() {
+            println!();
+            notify_phase_complete(finished, pomo.phase());
+        }
+        print!("\r{:?} {}", pomo.phase(), format_duration(pomo.remaining()));
+        let _ = io::stdout().flush();
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    let _ = pomo.stop();
+    println!();
+    let summary = pomo.summary();
+    println!(
+        "session summary: focused {}, breaks taken {}, cycles completed {}",
+        format_duration(Duration::from_millis(summary.focused_ms as u64)),
+        summary.breaks_taken,
+        summary.cycles_completed
+    );
+    if let Err(e) = export_pomodoro_summary(&summary, export_fmt) {
+        eprintln!("error: {:?} (--export {export_fmt})", e);
+        return ExitCode::from(2);
+    }
+    ExitCode::SUCCESS
+}
+
+fn notify_phase_complete(finished: PomodoroPhase, next: PomodoroPhase) {
+    let body = format!("{:?} selesai, mulai {:?}", finished, next);
+    let sent = notify_rust::Notification::new()
+        .summary("timer-cli pomodoro")
+        .body(&body)
+        .show()
+        .is_ok();
+    if !sent {
+        eprint!("\x07");
+        eprintln!("timer-cli: {body}");
+    }
+}
+
+fn run_watch(reg: &Arc<Mutex<TimerRegistry>>, name: &str) -> Result<(), TimerError> {
     // Jika belum berjalan, otomatis mulai (abaikan AlreadyRunning)
-    let _ = t.start();
+    let _ = reg.lock().unwrap().start(name);
 
     let stop_flag = Arc::new(AtomicBool::new(false));
     let stop_reader = stop_flag.clone();
@@ -125,24 +353,49 @@ fn run_watch(t: &mut Timer) {
     let _ = io::stdout().flush();
 
     while !stop_flag.load(Ordering::SeqCst) {
-        let d = t.elapsed();
-        print!("\r{}", format_duration(d));
+        let (elapsed, remaining) = {
+            let reg = reg.lock().unwrap();
+            (reg.elapsed(name)?, reg.remaining(name)?)
+        };
+        match remaining {
+            Some(r) => print!("\r{name}: {} remaining", format_duration(r)),
+            None => print!("\r{name}: {}", format_duration(elapsed)),
+        }
         let _ = io::stdout().flush();
         thread::sleep(Duration::from_millis(100));
     }
 
-    print!("\r{}\n\x1b[?25h", format_duration(t.elapsed()));
+    let elapsed = reg.lock().unwrap().elapsed(name)?;
+    print!("\r{name}: {}\n\x1b[?25h", format_duration(elapsed));
     let _ = io::stdout().flush();
+    Ok(())
+}
+
+/// Fire a desktop notification announcing that the named countdown elapsed;
+/// on systems with no notification daemon (headless CI, bare containers) fall
+/// back to an ANSI bell plus a plain stderr line so the expiry is never silent.
+fn notify_countdown_elapsed(name: &str, target: Duration) {
+    let body = format!("Countdown '{name}' ({}) finished", format_duration(target));
+    let sent = notify_rust::Notification::new()
+        .summary("timer-cli")
+        .body(&body)
+        .show()
+        .is_ok();
+    if !sent {
+        eprint!("\x07");
+        eprintln!("\ntimer-cli: {body}");
+    }
 }
 
-fn print_laps(t: &Timer) {
-    if t.laps().is_empty() {
+fn print_laps(reg: &TimerRegistry, name: &str) -> Result<(), TimerError> {
+    let laps = reg.laps(name)?;
+    if laps.is_empty() {
         println!("(no laps)");
-        return;
+        return Ok(());
     }
     println!("#  time          delta        label");
     let mut prev_ms: u128 = 0;
-    for lap in t.laps() {
+    for lap in laps {
         let delta_ms = lap.at_ms.saturating_sub(prev_ms);
         let at = Duration::from_millis(lap.at_ms as u64);
         let delta = Duration::from_millis(delta_ms as u64);
@@ -155,18 +408,33 @@ fn print_laps(t: &Timer) {
         );
         prev_ms = lap.at_ms;
     }
+    Ok(())
+}
+
+fn print_timers(reg: &TimerRegistry) {
+    let timers = reg.timers();
+    if timers.is_empty() {
+        println!("(no timers)");
+        return;
+    }
+    println!("name            elapsed       remaining");
+    for t in timers {
+        let remaining = t.remaining.map(format_duration).unwrap_or_else(|| "-".to_string());
+        println!("{:<15} {:<13} {}", t.name, format_duration(t.elapsed), remaining);
+    }
 }
 
-fn export_laps(t: &Timer, fmt: &str) -> Result<(), TimerError> {
+fn export_laps(reg: &TimerRegistry, name: &str, fmt: &str) -> Result<(), TimerError> {
+    let laps = reg.laps(name)?;
     match fmt {
         "json" => {
-            println!("{}", serde_json::to_string_pretty(&t.laps()).unwrap());
+            println!("{}", serde_json::to_string_pretty(&laps).unwrap());
             Ok(())
         }
         "csv" => {
             let mut wtr = csv::Writer::from_writer(std::io::stdout());
             wtr.write_record(["index", "time_ms", "label"]).unwrap();
-            for lap in t.laps() {
+            for lap in laps {
                 wtr.write_record([
                     lap.index.to_string(),
                     lap.at_ms.to_string(),
@@ -176,10 +444,89 @@ fn export_laps(t: &Timer, fmt: &str) -> Result<(), TimerError> {
             wtr.flush().unwrap();
             Ok(())
         }
+        "influx" => export_laps_influx(reg, name),
         _ => Err(TimerError(TimerErrorKind::Invalid)),
     }
 }
 
+/// Emit one InfluxDB line-protocol point per lap:
+/// `lap,label=<label> at_ms=<v>,delta_ms=<v> <unix_nanos>`. Laps only record
+/// time since the timer started, not wall-clock time, so each point's
+/// timestamp is reconstructed by walking back from "now" by how far behind
+/// the current elapsed time that lap was.
+fn export_laps_influx(reg: &TimerRegistry, name: &str) -> Result<(), TimerError> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|_| TimerError(TimerErrorKind::Invalid))?;
+    let elapsed = reg.elapsed(name)?;
+    let laps = reg.laps(name)?;
+    let deltas = reg.lap_deltas(name)?;
+    for (lap, &delta_ms) in laps.iter().zip(deltas.iter()) {
+        let lap_at = Duration::from_millis(lap.at_ms as u64);
+        let offset = elapsed.saturating_sub(lap_at);
+        let ts_ns = now.saturating_sub(offset).as_nanos();
+        let label = lap.label.clone().unwrap_or_else(|| lap.index.to_string());
+        println!("lap,label={} at_ms={},delta_ms={delta_ms} {ts_ns}", escape_influx_tag_value(&label), lap.at_ms);
+    }
+    Ok(())
+}
+
+/// Escape `,`, `=` and space in an InfluxDB line-protocol tag value, per the
+/// line protocol spec -- unescaped, any of those characters in a lap label
+/// (e.g. `lap default "step,one"`) would corrupt the emitted line.
+fn escape_influx_tag_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(',', "\\,").replace('=', "\\=").replace(' ', "\\ ")
+}
+
+/// Print a `Pomodoro::summary()` through the same format choice as
+/// `export_laps` (`json`/`csv`/`influx`), instead of hand-rolling a single
+/// pretty-printed JSON blob at the pomodoro command's call site.
+fn export_pomodoro_summary(summary: &timer_cli::PomodoroSummary, fmt: &str) -> Result<(), TimerError> {
+    match fmt {
+        "json" => {
+            println!("{}", serde_json::to_string_pretty(summary).unwrap());
+            Ok(())
+        }
+        "csv" => {
+            let mut wtr = csv::Writer::from_writer(std::io::stdout());
+            wtr.write_record(["focused_ms", "breaks_taken", "cycles_completed"]).unwrap();
+            wtr.write_record([
+                summary.focused_ms.to_string(),
+                summary.breaks_taken.to_string(),
+                summary.cycles_completed.to_string(),
+            ]).unwrap();
+            wtr.flush().unwrap();
+            Ok(())
+        }
+        "influx" => {
+            let ts_ns = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_err(|_| TimerError(TimerErrorKind::Invalid))?
+                .as_nanos();
+            println!(
+                "pomodoro_summary focused_ms={},breaks_taken={},cycles_completed={} {ts_ns}",
+                summary.focused_ms, summary.breaks_taken, summary.cycles_completed
+            );
+            Ok(())
+        }
+        _ => Err(TimerError(TimerErrorKind::Invalid)),
+    }
+}
+
+fn print_stats(reg: &TimerRegistry, name: &str) -> Result<(), TimerError> {
+    let deltas = reg.lap_deltas(name)?;
+    let stats = timer_cli::lap_stats(&deltas)?;
+    println!("laps   {}", stats.count);
+    println!("min    {}", format_duration(Duration::from_millis(stats.min_ms)));
+    println!("mean   {}", format_duration(Duration::from_millis(stats.mean_ms.round() as u64)));
+    println!("max    {}", format_duration(Duration::from_millis(stats.max_ms)));
+    println!("p50    {}", format_duration(Duration::from_millis(stats.p50_ms)));
+    println!("p90    {}", format_duration(Duration::from_millis(stats.p90_ms)));
+    println!("p99    {}", format_duration(Duration::from_millis(stats.p99_ms)));
+    println!("p99.9  {}", format_duration(Duration::from_millis(stats.p999_ms)));
+    Ok(())
+}
+
 fn measure_command(cmdline: Vec<String>) -> Result<(), TimerError> {
     if cmdline.is_empty() { return Err(TimerError(TimerErrorKind::Invalid)); }
     let mut t = Timer::new();
@@ -193,6 +540,6 @@ fn measure_command(cmdline: Vec<String>) -> Result<(), TimerError> {
 
 fn print_help() {
     println!(
-        "COMMANDS:\n  start                Mulai timer\n  stop                 Hentikan timer & akumulasi waktu\n  reset                Setel ulang ke 00:00:00.000 (hapus laps)\n  elapsed              Cetak waktu kumulatif\n  watch                Tampilkan waktu realtime (Enter untuk kembali)\n  lap [label]          Tambah lap (hanya saat running)\n  laps                 Tampilkan semua lap + delta\n  export [json|csv]    Cetak laps ke stdout (bisa di-pipe)\n  measure -- <cmd...>  Ukur durasi proses eksternal; exit code diteruskan\n  help                 Bantuan (REPL)\n  exit/quit            Keluar (REPL)\n\nMODES:\n  timer-cli run <cmds...>     # batch (exit code tegas)\n  timer-cli interactive       # REPL eksplisit\n  timer-cli <cmds...>         # kompat-lama (tanpa subcommand)\n  timer-cli                   # REPL default\n"
+        "COMMANDS (nama timer opsional, default \"{DEFAULT_TIMER}\"):\n  start [nama]                 Mulai timer\n  stop [nama]                  Hentikan timer & akumulasi waktu\n  reset [nama]                 Setel ulang ke 00:00:00.000 (hapus laps)\n  elapsed [nama]                Cetak waktu kumulatif\n  watch [nama]                 Tampilkan waktu realtime (Enter untuk kembali)\n  countdown [nama] <durasi>    Jadwalkan hitung mundur (background) + notifikasi saat habis (durasi: 25m, 1h30m, 90s, atau 00:25:00.000)\n  lap [nama] [label]           Tambah lap (hanya saat running)\n  laps [nama]                  Tampilkan semua lap + delta\n  stats [nama]                 Statistik lap delta (min/mean/max + p50/p90/p99/p99.9)\n  export [nama] [json|csv|influx]  Cetak laps ke stdout (bisa di-pipe)\n  timers                       Daftar semua timer aktif + elapsed/remaining\n  measure -- <cmd...>          Ukur durasi proses eksternal; exit code diteruskan\n  help                         Bantuan (REPL)\n  exit/quit                    Keluar (REPL)\n\nMODES:\n  timer-cli run <cmds...>     # batch (exit code tegas)\n  timer-cli interactive       # REPL eksplisit\n  timer-cli pomodoro [flags]  # sesi pomodoro (--work/--short-break/--long-break/--cycles/--export [json|csv|influx])\n  timer-cli daemon            # jalankan daemon di belakang Unix socket\n  timer-cli --daemon [--socket <path>] <cmds...>  # kirim perintah ke daemon yang jalan (flag di depan; lihat catatan di bawah)\n  timer-cli <cmds...>         # kompat-lama (tanpa subcommand)\n\nCatatan: pada bentuk kompat-lama di atas, `--daemon`/`--socket` harus ditulis\nSEBELUM daftar perintah (mis. `timer-cli --daemon elapsed`), bukan sesudahnya --\nsetelah token perintah pertama, sisanya diteruskan apa adanya. Untuk menaruh\nflag di posisi bebas, pakai `timer-cli run <cmds...> --daemon`.\n  timer-cli                   # REPL default\n"
     );
 }
@@ -0,0 +1,229 @@
+//! A registry of named `Timer`s, so a single process can run several
+//! independent stopwatches (`start build`, `start tests`, ...) instead of
+//! one implicit timer. Countdown expiry is scheduled on a `TimingWheel`
+//! rather than polled, so checking for due timers stays O(1) per tick
+//! regardless of how many timers are registered.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{Lap, Timer, TimerError, TimerErrorKind};
+
+/// Stable identity for a timer tracked by a `TimerRegistry`. Names can be
+/// reused across processes/restarts; the id is what a caller should hold
+/// onto if it needs to refer back to a specific timer unambiguously.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+struct WheelEntry {
+    id: TimerId,
+    /// Additional full revolutions of the wheel before this entry is due.
+    rounds: u64,
+}
+
+/// Stores pending countdown expirations in an array of buckets indexed by
+/// `(deadline_tick) % wheel_size`. Advancing the clock one tick only walks
+/// the current bucket, giving O(1) amortized insert and expiry instead of
+/// scanning every scheduled timer.
+pub struct TimingWheel {
+    buckets: Vec<Vec<WheelEntry>>,
+    tick: Duration,
+    current_tick: u64,
+}
+
+impl TimingWheel {
+    pub fn new(wheel_size: usize, tick: Duration) -> Self {
+        Self {
+            buckets: (0..wheel_size.max(1)).map(|_| Vec::new()).collect(),
+            tick,
+            current_tick: 0,
+        }
+    }
+
+    /// Schedule `id` to fire after `delay` (rounded up to a whole number of ticks).
+    pub fn schedule(&mut self, id: TimerId, delay: Duration) {
+        let tick_ms = self.tick.as_millis().max(1);
+        let wheel_size = self.buckets.len() as u64;
+        let ticks = delay.as_millis().div_ceil(tick_ms).max(1) as u64;
+        let deadline_tick = self.current_tick + ticks;
+        let bucket = (deadline_tick % wheel_size) as usize;
+        // `ticks` ticks from now means the bucket comes round
+        // `(ticks - 1) / wheel_size` times before the visit that's due --
+        // without the `- 1`, a `ticks` that's an exact multiple of
+        // `wheel_size` would be requeued for one extra, spurious revolution.
+        let rounds = (ticks - 1) / wheel_size;
+        self.buckets[bucket].push(WheelEntry { id, rounds });
+    }
+
+    /// Advance by one tick (~`self.tick` of wall time) and return the ids due
+    /// this tick. Entries not yet due (still with rounds left) are kept in
+    /// the bucket for the next full revolution.
+    pub fn advance(&mut self) -> Vec<TimerId> {
+        self.current_tick += 1;
+        let bucket_idx = (self.current_tick as usize) % self.buckets.len();
+        let pending = std::mem::take(&mut self.buckets[bucket_idx]);
+        let mut fired = Vec::new();
+        for mut entry in pending {
+            if entry.rounds == 0 {
+                fired.push(entry.id);
+            } else {
+                entry.rounds -= 1;
+                self.buckets[bucket_idx].push(entry);
+            }
+        }
+        fired
+    }
+}
+
+struct TimerEntry {
+    name: String,
+    timer: Timer,
+    countdown: Option<Duration>,
+}
+
+/// A named timer's current state, as reported by `TimerRegistry::timers`.
+#[derive(Debug, Clone)]
+pub struct TimerSnapshot {
+    pub id: TimerId,
+    pub name: String,
+    pub elapsed: Duration,
+    /// Time left until expiry, if this timer has an active countdown.
+    pub remaining: Option<Duration>,
+}
+
+/// Multiple independent `Timer`s addressed by name, with countdown expiry
+/// driven by a shared `TimingWheel` so one background tick can cover all of
+/// them instead of polling each one.
+pub struct TimerRegistry {
+    next_id: u64,
+    entries: HashMap<TimerId, TimerEntry>,
+    by_name: HashMap<String, TimerId>,
+    wheel: TimingWheel,
+}
+
+impl Default for TimerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimerRegistry {
+    /// Default wheel accuracy: a background thread ticking every 100ms drives
+    /// every registered countdown regardless of how many are scheduled.
+    pub const DEFAULT_TICK: Duration = Duration::from_millis(100);
+
+    pub fn new() -> Self {
+        Self {
+            next_id: 0,
+            entries: HashMap::new(),
+            by_name: HashMap::new(),
+            wheel: TimingWheel::new(1024, Self::DEFAULT_TICK),
+        }
+    }
+
+    fn alloc_id(&mut self) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    fn id_for(&self, name: &str) -> Result<TimerId, TimerError> {
+        self.by_name.get(name).copied().ok_or(TimerError(TimerErrorKind::Invalid))
+    }
+
+    /// Start the named timer, creating it (elapsed at zero) if it's new.
+    pub fn start(&mut self, name: &str) -> Result<TimerId, TimerError> {
+        if let Some(&id) = self.by_name.get(name) {
+            self.entries.get_mut(&id).unwrap().timer.start()?;
+            return Ok(id);
+        }
+        let id = self.alloc_id();
+        let mut timer = Timer::new();
+        timer.start()?;
+        self.entries.insert(id, TimerEntry { name: name.to_string(), timer, countdown: None });
+        self.by_name.insert(name.to_string(), id);
+        Ok(id)
+    }
+
+    pub fn stop(&mut self, name: &str) -> Result<(), TimerError> {
+        let id = self.id_for(name)?;
+        self.entries.get_mut(&id).unwrap().timer.stop()
+    }
+
+    pub fn reset(&mut self, name: &str) -> Result<(), TimerError> {
+        let id = self.id_for(name)?;
+        let entry = self.entries.get_mut(&id).unwrap();
+        entry.timer.reset();
+        entry.countdown = None;
+        Ok(())
+    }
+
+    pub fn elapsed(&self, name: &str) -> Result<Duration, TimerError> {
+        let id = self.id_for(name)?;
+        Ok(self.entries[&id].timer.elapsed())
+    }
+
+    pub fn lap(&mut self, name: &str, label: Option<String>) -> Result<(), TimerError> {
+        let id = self.id_for(name)?;
+        self.entries.get_mut(&id).unwrap().timer.lap(label)
+    }
+
+    pub fn laps(&self, name: &str) -> Result<&[Lap], TimerError> {
+        let id = self.id_for(name)?;
+        Ok(self.entries[&id].timer.laps())
+    }
+
+    pub fn lap_deltas(&self, name: &str) -> Result<Vec<u64>, TimerError> {
+        let id = self.id_for(name)?;
+        Ok(self.entries[&id].timer.lap_deltas())
+    }
+
+    /// Remaining time for the named timer's active countdown, if any.
+    pub fn remaining(&self, name: &str) -> Result<Option<Duration>, TimerError> {
+        let id = self.id_for(name)?;
+        let entry = &self.entries[&id];
+        Ok(entry.countdown.map(|target| entry.timer.countdown(target)))
+    }
+
+    /// Start the named timer (creating it if it's new) and schedule a countdown
+    /// notification `target` from now, via the timing wheel. Fails with
+    /// `AlreadyRunning` if the timer is already running. Non-blocking: call
+    /// `tick()` from a background thread to find out when it fires.
+    pub fn countdown(&mut self, name: &str, target: Duration) -> Result<TimerId, TimerError> {
+        let id = self.start(name)?;
+        self.entries.get_mut(&id).unwrap().countdown = Some(target);
+        self.wheel.schedule(id, target);
+        Ok(id)
+    }
+
+    /// Advance the wheel by one tick and return the `(name, target)` of every
+    /// countdown that just expired. Call roughly every `TimerRegistry::DEFAULT_TICK`.
+    pub fn tick(&mut self) -> Vec<(String, Duration)> {
+        let fired_ids = self.wheel.advance();
+        let mut fired = Vec::new();
+        for id in fired_ids {
+            if let Some(entry) = self.entries.get_mut(&id) {
+                if let Some(target) = entry.countdown.take() {
+                    fired.push((entry.name.clone(), target));
+                }
+            }
+        }
+        fired
+    }
+
+    /// Snapshot of every tracked timer, sorted by name.
+    pub fn timers(&self) -> Vec<TimerSnapshot> {
+        let mut out: Vec<TimerSnapshot> = self
+            .entries
+            .iter()
+            .map(|(&id, entry)| TimerSnapshot {
+                id,
+                name: entry.name.clone(),
+                elapsed: entry.timer.elapsed(),
+                remaining: entry.countdown.map(|target| entry.timer.countdown(target)),
+            })
+            .collect();
+        out.sort_by(|a, b| a.name.cmp(&b.name));
+        out
+    }
+}
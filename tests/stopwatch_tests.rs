@@ -1,26 +1,26 @@
-use stopwatch_rust::{format_duration, Stopwatch, StopwatchErrorKind};
+use timer_cli::{format_duration, Timer, TimerErrorKind};
 
 #[test]
 fn stop_without_start_should_error() {
-    let mut sw = Stopwatch::new();
-    let err = sw.stop().unwrap_err();
-    assert_eq!(err.0, StopwatchErrorKind::NotRunning);
+    let mut t = Timer::new();
+    let err = t.stop().unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::NotRunning);
 }
 
 #[test]
 fn double_start_should_error() {
-    let mut sw = Stopwatch::new();
-    sw.start().unwrap();
-    let err = sw.start().unwrap_err();
-    assert_eq!(err.0, StopwatchErrorKind::AlreadyRunning);
+    let mut t = Timer::new();
+    t.start().unwrap();
+    let err = t.start().unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::AlreadyRunning);
 }
 
 #[test]
 fn reset_while_running_sets_zero_and_not_running() {
-    let mut sw = Stopwatch::new();
-    sw.start().unwrap();
-    sw.reset();
-    assert_eq!(sw.elapsed().as_millis(), 0);
+    let mut t = Timer::new();
+    t.start().unwrap();
+    t.reset();
+    assert_eq!(t.elapsed().as_millis(), 0);
 }
 
 #[test]
@@ -28,3 +28,214 @@ fn format_is_stable_in_ms() {
     assert_eq!(format_duration(std::time::Duration::from_millis(1)), "00:00:00.001");
     assert_eq!(format_duration(std::time::Duration::from_millis(10)), "00:00:00.010");
 }
+
+#[test]
+fn parse_duration_accepts_compact_form() {
+    use std::time::Duration;
+    use timer_cli::parse_duration;
+
+    assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+    assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse_duration("2m30s").unwrap(), Duration::from_secs(150));
+    assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+}
+
+#[test]
+fn parse_duration_accepts_colon_form_with_either_decimal_separator() {
+    use timer_cli::parse_duration;
+
+    let dotted = parse_duration("00:01:05.250").unwrap();
+    let comma = parse_duration("00:01:05,250").unwrap();
+    assert_eq!(dotted, comma);
+    assert_eq!(dotted.as_millis(), 65_250);
+    assert_eq!(parse_duration(":30").unwrap().as_millis(), 30_000);
+}
+
+#[test]
+fn parse_duration_round_trips_with_format_duration() {
+    use std::time::Duration;
+    use timer_cli::parse_duration;
+
+    let d = Duration::from_millis(3_725_125);
+    assert_eq!(parse_duration(&format_duration(d)).unwrap(), d);
+}
+
+#[test]
+fn parse_duration_rejects_unknown_units() {
+    use timer_cli::{parse_duration, TimerErrorKind};
+
+    let err = parse_duration("5x").unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::Invalid);
+}
+
+#[test]
+fn parse_duration_colon_form_rejects_overflow_instead_of_panicking() {
+    use timer_cli::{parse_duration, TimerErrorKind};
+
+    let err = parse_duration(&format!("{}:00:00", u64::MAX)).unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::Invalid);
+}
+
+#[test]
+fn parse_duration_compact_form_rejects_overflow_instead_of_panicking() {
+    use timer_cli::{parse_duration, TimerErrorKind};
+
+    // Each `u64::MAX`-ms token alone fits in a Duration; summing two of them
+    // overflows the running total.
+    let huge = format!("{0}ms{0}ms", u64::MAX);
+    let err = parse_duration(&huge).unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::Invalid);
+}
+
+#[test]
+fn lap_stats_reports_percentiles() {
+    use timer_cli::lap_stats;
+
+    let deltas = vec![10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+    let stats = lap_stats(&deltas).unwrap();
+    assert_eq!(stats.count, 10);
+    assert_eq!(stats.min_ms, 10);
+    assert_eq!(stats.max_ms, 100);
+    assert_eq!(stats.p99_ms, 100);
+}
+
+#[test]
+fn lap_stats_rejects_empty_input() {
+    use timer_cli::{lap_stats, TimerErrorKind};
+
+    let err = lap_stats(&[]).unwrap_err();
+    assert_eq!(err.0, TimerErrorKind::Invalid);
+}
+
+#[test]
+fn registry_tracks_named_timers_independently() {
+    use timer_cli::TimerRegistry;
+
+    let mut reg = TimerRegistry::new();
+    reg.start("build").unwrap();
+    reg.start("tests").unwrap();
+    reg.lap("tests", None).unwrap();
+
+    assert_eq!(reg.laps("tests").unwrap().len(), 1);
+    assert_eq!(reg.laps("build").unwrap().len(), 0);
+    assert!(reg.elapsed("build").is_ok());
+    assert!(reg.elapsed("missing").is_err());
+}
+
+#[test]
+fn registry_countdown_fires_after_enough_ticks() {
+    use std::time::Duration;
+    use timer_cli::TimerRegistry;
+
+    let mut reg = TimerRegistry::new();
+    reg.countdown("kitchen", Duration::from_millis(250)).unwrap();
+
+    let mut fired = Vec::new();
+    for _ in 0..10 {
+        fired.extend(reg.tick());
+    }
+    assert_eq!(fired, vec![("kitchen".to_string(), Duration::from_millis(250))]);
+}
+
+#[test]
+fn registry_countdown_fires_on_first_pass_at_a_wheel_size_multiple() {
+    use std::time::Duration;
+    use timer_cli::TimerRegistry;
+
+    // Default wheel is 1024 buckets x 100ms ticks. A delay of exactly
+    // 102_400ms is precisely 1024 ticks -- the boundary where a rounds
+    // off-by-one would requeue the entry for one extra, spurious revolution
+    // (2048 ticks) instead of firing on the first.
+    let mut reg = TimerRegistry::new();
+    reg.countdown("coffee", Duration::from_millis(102_400)).unwrap();
+
+    let mut fired = Vec::new();
+    for _ in 0..1024 {
+        fired.extend(reg.tick());
+    }
+    assert_eq!(fired, vec![("coffee".to_string(), Duration::from_millis(102_400))]);
+}
+
+#[test]
+fn timer_countdown_saturates_at_zero_past_target() {
+    use std::time::Duration;
+    use timer_cli::Timer;
+
+    let t = Timer::new();
+    assert_eq!(t.countdown(Duration::from_secs(10)), Duration::from_secs(10));
+    // elapsed() on a fresh, never-started timer is zero, so a zero target has
+    // already been reached: nothing remains.
+    assert_eq!(t.countdown(Duration::ZERO), Duration::ZERO);
+
+    // A running timer that is already past its 1ms target must read zero
+    // rather than underflow.
+    let mut running = Timer::new();
+    running.start().unwrap();
+    std::thread::sleep(Duration::from_millis(5));
+    assert_eq!(running.countdown(Duration::from_millis(1)), Duration::ZERO);
+}
+
+#[test]
+fn pomodoro_every_nth_break_is_long() {
+    use std::time::Duration;
+    use timer_cli::{Pomodoro, PomodoroConfig, PomodoroPhase};
+
+    let config = PomodoroConfig {
+        work: Duration::from_secs(1),
+        short_break: Duration::from_secs(1),
+        long_break: Duration::from_secs(1),
+        cycles_before_long_break: 2,
+    };
+    let mut pomo = Pomodoro::new(config);
+
+    // cycle 1: work -> short break
+    pomo.skip();
+    assert_eq!(pomo.phase(), PomodoroPhase::ShortBreak);
+    assert_eq!(pomo.cycle(), 1);
+    pomo.skip(); // back to work
+
+    // cycle 2: work -> long break (2nd cycle, cadence == 2)
+    pomo.skip();
+    assert_eq!(pomo.phase(), PomodoroPhase::LongBreak);
+    assert_eq!(pomo.cycle(), 2);
+}
+
+#[test]
+fn pomodoro_summary_counts_breaks_and_cycles() {
+    use std::time::Duration;
+    use timer_cli::{Pomodoro, PomodoroConfig};
+
+    let config = PomodoroConfig {
+        work: Duration::from_secs(1),
+        short_break: Duration::from_secs(1),
+        long_break: Duration::from_secs(1),
+        cycles_before_long_break: 4,
+    };
+    let mut pomo = Pomodoro::new(config);
+
+    pomo.skip(); // work -> short break
+    pomo.skip(); // short break -> work
+
+    let summary = pomo.summary();
+    assert_eq!(summary.cycles_completed, 1);
+    assert_eq!(summary.breaks_taken, 1);
+}
+
+#[test]
+fn pomodoro_cycles_before_long_break_of_zero_does_not_divide_by_zero() {
+    use std::time::Duration;
+    use timer_cli::{Pomodoro, PomodoroConfig, PomodoroPhase};
+
+    // cycles_before_long_break == 0 would make `cycle % cadence` a division
+    // by zero; `finish_phase` clamps the cadence to at least 1.
+    let config = PomodoroConfig {
+        work: Duration::from_secs(1),
+        short_break: Duration::from_secs(1),
+        long_break: Duration::from_secs(1),
+        cycles_before_long_break: 0,
+    };
+    let mut pomo = Pomodoro::new(config);
+
+    pomo.skip();
+    assert_eq!(pomo.phase(), PomodoroPhase::LongBreak);
+}